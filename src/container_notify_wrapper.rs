@@ -1,11 +1,10 @@
-use distrobox_run_notify::SimpleLogger;
-use log::{error, info, LevelFilter};
+use distrobox_run_notify::{LogRecord, SimpleLogger};
+use log::{error, info, warn, LevelFilter};
 use std::env;
 use std::fs;
-use std::io;
+use std::io::{self, BufRead, BufReader};
 use std::os::unix::net::UnixDatagram;
-use std::os::unix::process::CommandExt;
-use std::process::{self, Command};
+use std::process::{self, Command, Stdio};
 use std::thread;
 use std::time::Duration;
 
@@ -41,26 +40,36 @@ fn main() {
 
     let shared_dir = env::var("SHARED_DIR").unwrap_or_else(|_| "/shared".to_string());
     let socket_path = format!("{}/notify.sock", shared_dir);
-    let status_file = format!("{}/container-status", shared_dir);
+    let events_socket_path = format!("{}/events.sock", shared_dir);
     let pid_file = format!("{}/container-pid", shared_dir);
+    let logs_socket_path = format!("{}/logs.sock", shared_dir);
+
+    // container-status is a legacy polling artifact; only kept around for
+    // tools that haven't moved to events_socket_path yet
+    let status_file_compat = env::var("STATUS_FILE_COMPAT").as_deref() == Ok("1");
+    let status_file = format!("{}/container-status", shared_dir);
 
     info!("Starting container wrapper for: {}", args[1]);
     info!("Shared directory: {}", shared_dir);
     info!("Socket path: {}", socket_path);
+    info!("Events socket path: {}", events_socket_path);
+    info!("Logs socket path: {}", logs_socket_path);
 
     // Create shared directory
     info!("Creating shared directory");
     fs::create_dir_all(&shared_dir)
         .expect("Failed to create shared directory");
-    
-    // Write PID and status
+
+    // Write PID
     info!("Writing PID {} to {}", process::id(), pid_file);
     fs::write(&pid_file, process::id().to_string())
         .expect("Failed to write PID file");
-        
-    info!("Writing initial status");
-    fs::write(&status_file, "STARTING")
-        .expect("Failed to write initial status");
+
+    if status_file_compat {
+        info!("Writing initial status (STATUS_FILE_COMPAT=1)");
+        fs::write(&status_file, "STARTING")
+            .expect("Failed to write initial status");
+    }
 
     // Remove old socket
     info!("Removing old socket if present");
@@ -83,7 +92,8 @@ fn main() {
         0 => {
             // Child - run proxy
             info!("Starting socket proxy process");
-            if let Err(e) = run_socket_proxy(socket, status_file) {
+            let status_file = if status_file_compat { Some(status_file) } else { None };
+            if let Err(e) = run_socket_proxy(socket, events_socket_path, status_file) {
                 error!("Socket proxy failed: {}", e);
                 process::exit(1);
             }
@@ -110,26 +120,89 @@ fn main() {
                 env::set_var("NOTIFY_SOCKET", &socket_path);
             }
             info!("Starting main process with NOTIFY_SOCKET={}", socket_path);
-            
-            // Exec target
-            info!("Executing target command: {}", args[1]);
-            let error = Command::new(&args[1]).args(&args[2..]).exec();
-            error!("Failed to exec {}: {}", args[1], error);
-            process::exit(1);
+
+            // Spawn target, capturing stderr so it can be forwarded over logs_socket_path
+            info!("Starting target command: {}", args[1]);
+            let mut target = Command::new(&args[1])
+                .args(&args[2..])
+                .stderr(Stdio::piped())
+                .spawn()
+                .unwrap_or_else(|e| {
+                    error!("Failed to start {}: {}", args[1], e);
+                    process::exit(1);
+                });
+
+            let target_stderr = target.stderr.take().expect("stderr was piped");
+            thread::spawn(move || forward_logs(target_stderr, &logs_socket_path));
+
+            let status = target.wait().unwrap_or_else(|e| {
+                error!("Failed to wait on {}: {}", args[1], e);
+                process::exit(1);
+            });
+            process::exit(status.code().unwrap_or(1));
         }
     }
 }
 
-fn run_socket_proxy(socket: UnixDatagram, status_file: String) -> io::Result<()> {
+// Reads lines from the target's stderr and forwards each as a LogRecord
+// datagram to logs_socket_path, so the host can replay them through its logger.
+fn forward_logs(stderr: impl io::Read, logs_socket_path: &str) {
+    let sender = match UnixDatagram::unbound() {
+        Ok(sender) => sender,
+        Err(e) => {
+            error!("Failed to create logs socket: {}", e);
+            return;
+        }
+    };
+
+    for line in BufReader::new(stderr).lines() {
+        let line = match line {
+            Ok(line) => line,
+            Err(e) => {
+                warn!("Failed to read target stderr: {}", e);
+                break;
+            }
+        };
+
+        let record = LogRecord::from_line(&line);
+        let payload = match serde_json::to_vec(&record) {
+            Ok(payload) => payload,
+            Err(e) => {
+                warn!("Failed to serialize log record: {}", e);
+                continue;
+            }
+        };
+
+        if let Err(e) = sender.send_to(&payload, logs_socket_path) {
+            warn!("Failed to forward log line to {}: {}", logs_socket_path, e);
+        }
+    }
+}
+
+// Relays sd_notify lines from the target straight to the host's
+// events_socket_path, reacting the instant each frame arrives instead of
+// waiting on a file poll. The status file, if enabled, is only an optional
+// compatibility snapshot of the last event.
+fn run_socket_proxy(
+    socket: UnixDatagram,
+    events_socket_path: String,
+    status_file: Option<String>,
+) -> io::Result<()> {
     info!("Socket proxy listening...");
+    let events_sender = UnixDatagram::unbound()?;
     let mut buffer = [0u8; 4096];
-    
+
     loop {
         match socket.recv(&mut buffer) {
             Ok(size) => {
                 let message = String::from_utf8_lossy(&buffer[..size]);
                 info!("Raw message received: {:?}", message);
-                if let Err(e) = process_message(&message, &status_file) {
+                if let Err(e) = process_message(
+                    &message,
+                    &events_sender,
+                    &events_socket_path,
+                    status_file.as_deref(),
+                ) {
                     error!("Failed to process message: {}", e);
                 }
             }
@@ -142,45 +215,60 @@ fn run_socket_proxy(socket: UnixDatagram, status_file: String) -> io::Result<()>
     Ok(())
 }
 
-fn process_message(message: &str, status_file: &str) -> io::Result<()> {
+fn process_message(
+    message: &str,
+    events_sender: &UnixDatagram,
+    events_socket_path: &str,
+    status_file: Option<&str>,
+) -> io::Result<()> {
     info!("Processing message: {:?}", message);
-    
+
     for line in message.lines() {
         let line = line.trim();
-        if line.is_empty() { 
+        if line.is_empty() {
             info!("Skipping empty line");
-            continue; 
+            continue;
         }
-        
+
         info!("Processing line: {}", line);
-        
-        let content = match line {
+
+        let event = match line {
             "READY=1" => {
                 info!("Process signaled ready!");
-                "READY"
-            },
+                "READY".to_string()
+            }
             "STOPPING=1" => {
                 info!("Process is stopping");
-                "STOPPING"
-            },
+                "STOPPING".to_string()
+            }
             "WATCHDOG=1" => {
                 info!("Watchdog ping received");
-                "WATCHDOG"
-            },
+                "WATCHDOG".to_string()
+            }
+            _ if line.starts_with("EXTEND_TIMEOUT_USEC=") => {
+                let usec = &line["EXTEND_TIMEOUT_USEC=".len()..];
+                info!("Extend timeout requested: {} usec", usec);
+                format!("EXTEND:{}", usec)
+            }
             _ if line.starts_with("STATUS=") => {
                 info!("Status update: {}", line);
-                info!("Writing to status file: STATUS:{}", line);
-                return fs::write(status_file, format!("STATUS:{}", line));
+                format!("STATUS:{}", line)
             }
             _ => {
                 info!("Other message: {}", line);
-                info!("Writing to status file: MESSAGE:{}", line);
-                return fs::write(status_file, format!("MESSAGE:{}", line));
+                format!("MESSAGE:{}", line)
             }
         };
-        
-        info!("Writing to status file: {}", content);
-        fs::write(status_file, content)?;
+
+        info!("Forwarding event to host: {}", event);
+        if let Err(e) = events_sender.send_to(event.as_bytes(), events_socket_path) {
+            error!("Failed to forward event to {}: {}", events_socket_path, e);
+        }
+
+        if let Some(status_file) = status_file {
+            info!("Writing compatibility snapshot to status file: {}", event);
+            fs::write(status_file, &event)?;
+        }
     }
     Ok(())
 }
\ No newline at end of file
@@ -1,11 +1,70 @@
-use distrobox_run_notify::SimpleLogger;
-use log::{error, info, LevelFilter};
+use distrobox_run_notify::{reexec, restore_from_env, LogRecord, ReloadState, SimpleLogger};
+use log::{error, info, Level, LevelFilter};
+use std::collections::{HashMap, HashSet};
 use std::env;
 use std::fs;
-use std::process::{self, Command};
+use std::io;
+use std::os::unix::net::UnixDatagram;
+use std::process::{self, Child, Command};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc, Mutex};
 use std::thread;
 use std::time::{Duration, Instant};
 
+static RELOAD_REQUESTED: AtomicBool = AtomicBool::new(false);
+
+extern "C" fn handle_sighup(_: libc::c_int) {
+    RELOAD_REQUESTED.store(true, Ordering::SeqCst);
+}
+
+// Either a child we spawned ourselves, or one we re-attached to by PID after
+// a SIGHUP reload (in which case we never called fork/exec for it and so
+// hold no `Child` handle, only its PID).
+enum Container {
+    Spawned(Child),
+    Reattached { pid: u32 },
+}
+
+impl Container {
+    fn id(&self) -> u32 {
+        match self {
+            Container::Spawned(child) => child.id(),
+            Container::Reattached { pid } => *pid,
+        }
+    }
+
+    fn try_wait(&mut self) -> io::Result<Option<i32>> {
+        match self {
+            Container::Spawned(child) => Ok(child
+                .try_wait()?
+                .map(|status| status.code().unwrap_or(1))),
+            Container::Reattached { pid } => {
+                let mut status: libc::c_int = 0;
+                let result = unsafe { libc::waitpid(*pid as libc::pid_t, &mut status, libc::WNOHANG) };
+                if result == 0 {
+                    Ok(None)
+                } else if result == *pid as libc::c_int {
+                    Ok(Some(libc::WEXITSTATUS(status)))
+                } else {
+                    Err(io::Error::last_os_error())
+                }
+            }
+        }
+    }
+
+    fn kill(&mut self) {
+        match self {
+            Container::Spawned(child) => {
+                let _ = child.kill();
+            }
+            Container::Reattached { pid } => unsafe {
+                libc::kill(*pid as libc::pid_t, libc::SIGKILL);
+            },
+        }
+    }
+}
+
 fn notify_ready() -> Result<(), Box<dyn std::error::Error>> {
     info!("Sending READY=1 notification to systemd");
     sd_notify::notify(false, &[sd_notify::NotifyState::Ready])?;
@@ -30,15 +89,99 @@ fn notify_status(status: &str) -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+// Pings systemd's watchdog at interval/2 as long as the container has shown
+// signs of life within the last `interval`; once it goes stale we withhold
+// the ping so systemd's own watchdog timeout restarts the unit.
+fn run_watchdog(interval: Duration, last_heard: Arc<Mutex<Instant>>) {
+    info!("Starting watchdog keep-alive thread (interval: {:?})", interval);
+    loop {
+        thread::sleep(interval / 2);
+
+        let elapsed = last_heard.lock().unwrap().elapsed();
+        if elapsed > interval {
+            error!(
+                "No activity from container in {:?} (watchdog interval {:?}), withholding keep-alive",
+                elapsed, interval
+            );
+            continue;
+        }
+
+        if let Err(e) = notify_watchdog() {
+            error!("Failed to send watchdog ping: {}", e);
+        }
+    }
+}
+
+// Like `run_watchdog`, but for the supervisor: every unit must individually
+// show signs of life within `interval`, so one lively unit can't mask
+// another that's gone stale or dead.
+fn run_watchdog_multi(interval: Duration, last_heard: Arc<Mutex<HashMap<String, Instant>>>) {
+    info!("Starting watchdog keep-alive thread (interval: {:?})", interval);
+    loop {
+        thread::sleep(interval / 2);
+
+        let stale: Vec<(String, Duration)> = last_heard
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|(_, seen)| seen.elapsed() > interval)
+            .map(|(name, seen)| (name.clone(), seen.elapsed()))
+            .collect();
+
+        if !stale.is_empty() {
+            for (name, elapsed) in &stale {
+                error!(
+                    "No activity from unit '{}' in {:?} (watchdog interval {:?})",
+                    name, elapsed, interval
+                );
+            }
+            error!("{} unit(s) stale, withholding keep-alive", stale.len());
+            continue;
+        }
+
+        if let Err(e) = notify_watchdog() {
+            error!("Failed to send watchdog ping: {}", e);
+        }
+    }
+}
+
+// Receives LogRecord datagrams forwarded from the container wrapper and
+// replays them through the log facade, so they respect --verbose. `name` is
+// prefixed alongside "(container)" so multiplexed supervisor units (which
+// otherwise all look alike) can be told apart; the single-container caller
+// just passes "container".
+fn run_log_forwarder(name: &str, socket: UnixDatagram) {
+    let mut buffer = [0u8; 4096];
+    loop {
+        let size = match socket.recv(&mut buffer) {
+            Ok(size) => size,
+            Err(e) => {
+                error!("[{}] Logs socket error: {}", name, e);
+                break;
+            }
+        };
+
+        match serde_json::from_slice::<LogRecord>(&buffer[..size]) {
+            Ok(record) => {
+                let level = Level::from_str(&record.level).unwrap_or(Level::Info);
+                log::log!(level, "[{}] (container) {}", name, record.message);
+            }
+            Err(_) => {
+                info!("[{}] (container) {}", name, String::from_utf8_lossy(&buffer[..size]));
+            }
+        }
+    }
+}
+
 fn main() {
     let mut args: Vec<String> = env::args().collect();
-    
+
     // Check for verbose flag
     let verbose = args.contains(&"--verbose".to_string());
     if verbose {
         args.retain(|x| x != "--verbose");
     }
-    
+
     // Initialize logger
     let log_level = if verbose {
         LevelFilter::Info
@@ -46,32 +189,52 @@ fn main() {
         LevelFilter::Error
     };
     SimpleLogger::init(log_level);
-    
+
     if verbose {
         info!("Verbose mode enabled");
     }
-    
+
+    // Repeated `--unit <name> -- <cmd...>` groups put us in supervisor mode,
+    // gating readiness on every unit instead of a single container.
+    if args.iter().any(|a| a == "--unit") {
+        run_supervisor_mode(&args);
+        return;
+    }
+
     if args.len() < 2 {
         eprintln!("Usage: {} [--verbose] <container-command>", args[0]);
+        eprintln!("       {} [--verbose] --unit <name> -- <cmd...> [--unit <name> -- <cmd...> ...]", args[0]);
         eprintln!("Environment variables:");
         eprintln!("  SHARED_DIR=/path/to/shared  - Shared directory (default: /tmp/container-notify)");
         eprintln!("  TIMEOUT=60                  - Timeout in seconds");
         process::exit(1);
     }
 
+    run_single(args);
+}
+
+fn run_single(args: Vec<String>) {
     // Get configuration from environment
     let shared_dir = env::var("SHARED_DIR")
         .unwrap_or_else(|_| "/tmp/container-notify".to_string());
-    let status_file = format!("{}/container-status", shared_dir);
     let _pid_file = format!("{}/container-pid", shared_dir);
+    let logs_socket_path = format!("{}/logs.sock", shared_dir);
+    let events_socket_path = format!("{}/events.sock", shared_dir);
     let timeout: u64 = env::var("TIMEOUT")
         .unwrap_or_else(|_| "60".to_string())
         .parse()
         .unwrap_or(60);
+    let watchdog_interval: Option<Duration> = env::var("WATCHDOG_USEC")
+        .ok()
+        .and_then(|v| v.parse::<u64>().ok())
+        .map(Duration::from_micros);
 
     info!("Starting host waiter for container command: {}", args[1..].join(" "));
     info!("Shared directory: {}", shared_dir);
     info!("Timeout: {}s", timeout);
+    if let Some(interval) = watchdog_interval {
+        info!("Watchdog interval: {:?} (WATCHDOG_USEC set)", interval);
+    }
 
     // Check if we can communicate with systemd
     info!("Checking systemd notification availability");
@@ -83,7 +246,7 @@ fn main() {
     info!("Creating shared directory");
     fs::create_dir_all(&shared_dir)
         .expect("Failed to create shared directory");
-    
+
     info!("Setting directory permissions");
     #[cfg(unix)]
     {
@@ -96,15 +259,55 @@ fn main() {
             .expect("Failed to set directory permissions");
     }
 
-    // Start the container command in background
-    info!("Starting container process");
-    let mut child = Command::new(&args[1])
-        .args(&args[2..])
-        .spawn()
-        .expect("Failed to start container command");
+    // A SIGHUP means a previous incarnation of this process handed us its
+    // live state (sockets + container PID) via execve; otherwise this is a
+    // normal startup and we bind both sockets and spawn the container.
+    let reload_state: Option<ReloadState> = restore_from_env();
+    let (logs_socket, events_socket, mut container, mut ready_sent) = if let Some(state) = reload_state {
+        info!(
+            "Resuming after reload: reattaching to container PID {} (ready_sent={})",
+            state.container_pid, state.ready_sent
+        );
+        (
+            state.logs_socket,
+            state.events_socket,
+            Container::Reattached { pid: state.container_pid },
+            state.ready_sent,
+        )
+    } else {
+        info!("Opening logs socket at {}", logs_socket_path);
+        let _ = fs::remove_file(&logs_socket_path);
+        let logs_socket = UnixDatagram::bind(&logs_socket_path)
+            .expect("Failed to create logs socket");
+
+        info!("Opening events socket at {}", events_socket_path);
+        let _ = fs::remove_file(&events_socket_path);
+        let events_socket = UnixDatagram::bind(&events_socket_path)
+            .expect("Failed to create events socket");
+
+        info!("Starting container process");
+        let child = Command::new(&args[1])
+            .args(&args[2..])
+            .spawn()
+            .expect("Failed to start container command");
+
+        (logs_socket, events_socket, Container::Spawned(child), false)
+    };
+
+    let container_pid = container.id();
+    info!("Container PID {}", container_pid);
+
+    let logs_socket_for_reload = logs_socket
+        .try_clone()
+        .expect("Failed to clone logs socket");
+    thread::spawn(move || run_log_forwarder("container", logs_socket));
 
-    let container_pid = child.id();
-    info!("Container started with PID {}", container_pid);
+    let events_socket_for_reload = events_socket
+        .try_clone()
+        .expect("Failed to clone events socket");
+    events_socket
+        .set_read_timeout(Some(Duration::from_millis(200)))
+        .expect("Failed to set events socket read timeout");
 
     // Set up cleanup handler
     let shared_dir_cleanup = shared_dir.clone();
@@ -114,30 +317,54 @@ fn main() {
         process::exit(1);
     }).expect("Failed to set signal handler");
 
+    // SIGHUP triggers a graceful reload instead of a restart
+    unsafe {
+        libc::signal(libc::SIGHUP, handle_sighup as usize);
+    }
+
     // Monitor for ready signal and status updates
     info!("Waiting for container to be ready (timeout: {}s)", timeout);
-    
+
     let start_time = Instant::now();
-    let mut ready_sent = false;
-    let mut last_status = String::new();
-    
+    let mut effective_timeout = Duration::from_secs(timeout);
+    let last_heard = Arc::new(Mutex::new(Instant::now()));
+
+    // A reload can resume a container that had already sent READY, in which
+    // case the READY arm below never re-fires and would otherwise leave the
+    // unit with no watchdog thread even though WATCHDOG_USEC is still set.
+    if ready_sent {
+        if let Some(interval) = watchdog_interval {
+            info!("Reload resumed an already-ready container, restarting watchdog keep-alive");
+            let last_heard = Arc::clone(&last_heard);
+            thread::spawn(move || run_watchdog(interval, last_heard));
+        }
+    }
+
     loop {
-        let elapsed = start_time.elapsed().as_secs();
-        
+        if RELOAD_REQUESTED.swap(false, Ordering::SeqCst) {
+            info!("SIGHUP received, performing graceful reload");
+            let err = reexec(
+                &logs_socket_for_reload,
+                &events_socket_for_reload,
+                container_pid,
+                ready_sent,
+            );
+            error!("Failed to re-exec for reload: {}", err);
+        }
+
         // Check timeout
-        if elapsed >= timeout && !ready_sent {
+        if start_time.elapsed() >= effective_timeout && !ready_sent {
             error!("Timeout waiting for container to be ready");
-            let _ = child.kill();
+            container.kill();
             process::exit(1);
         }
-        
+
         // Check if container is still running
-        match child.try_wait() {
-            Ok(Some(exit_status)) => {
+        match container.try_wait() {
+            Ok(Some(exit_code)) => {
                 info!("Container process ended");
-                let exit_code = exit_status.code().unwrap_or(1);
                 info!("Container exited with code {}", exit_code);
-                
+
                 // Cleanup
                 let _ = fs::remove_dir_all(&shared_dir);
                 process::exit(exit_code);
@@ -152,13 +379,16 @@ fn main() {
             }
         }
         
-        // Check status file for updates
-        if let Ok(status) = fs::read_to_string(&status_file) {
-            let status = status.trim();
-            if status != last_status {
-                info!("Status changed from '{}' to '{}'", last_status, status);
-                
-                match status {
+        // Block on the events socket until a frame arrives or the read
+        // timeout ticks over; this is what used to be a 500ms file poll.
+        let mut buffer = [0u8; 4096];
+        match events_socket.recv(&mut buffer) {
+            Ok(size) => {
+                let status = String::from_utf8_lossy(&buffer[..size]).trim().to_string();
+                info!("Event received: {}", status);
+                *last_heard.lock().unwrap() = Instant::now();
+
+                match status.as_str() {
                     "READY" => {
                         if !ready_sent {
                             info!("Container is ready! Notifying systemd...");
@@ -166,6 +396,10 @@ fn main() {
                                 error!("Failed to notify systemd ready: {}", e);
                             } else {
                                 ready_sent = true;
+                                if let Some(interval) = watchdog_interval {
+                                    let last_heard = Arc::clone(&last_heard);
+                                    thread::spawn(move || run_watchdog(interval, last_heard));
+                                }
                             }
                         }
                     }
@@ -188,6 +422,16 @@ fn main() {
                             error!("Failed to forward status: {}", e);
                         }
                     }
+                    status if status.starts_with("EXTEND:") => {
+                        let usec_str = &status[7..]; // Remove "EXTEND:" prefix
+                        if let Ok(usec) = usec_str.parse::<u64>() {
+                            let extension = Duration::from_micros(usec);
+                            info!("Extending readiness deadline by {:?}", extension);
+                            effective_timeout += extension;
+                        } else {
+                            error!("Invalid EXTEND_TIMEOUT_USEC value: {}", status);
+                        }
+                    }
                     status if status.starts_with("MESSAGE:") => {
                         let msg = &status[8..]; // Remove "MESSAGE:" prefix
                         info!("Container message: {}", msg);
@@ -196,7 +440,7 @@ fn main() {
                         let exit_code_str = &status[5..]; // Remove "EXIT:" prefix
                         if let Ok(exit_code) = exit_code_str.parse::<i32>() {
                             info!("Container signaled exit with code {}", exit_code);
-                            let _ = child.kill();
+                            container.kill();
                             let _ = fs::remove_dir_all(&shared_dir);
                             process::exit(exit_code);
                         } else {
@@ -207,12 +451,393 @@ fn main() {
                         info!("Unknown status: {}", status);
                     }
                 }
-                last_status = status.to_string();
             }
-        } else {
-            info!("Status file not found yet");
+            Err(e) if e.kind() == io::ErrorKind::WouldBlock || e.kind() == io::ErrorKind::TimedOut => {
+                // No event within the read timeout; loop around to re-check
+                // the reload flag, deadline, and container liveness.
+            }
+            Err(e) => {
+                error!("Events socket error: {}", e);
+                process::exit(1);
+            }
         }
-        
-        thread::sleep(Duration::from_millis(500));
     }
-}
\ No newline at end of file
+}
+
+// One `--unit <name> -- <cmd...>` group.
+struct UnitSpec {
+    name: String,
+    cmd: Vec<String>,
+}
+
+// Splits `--unit <name> -- <cmd...>` groups out of the supervisor's
+// arguments. `args` excludes the program name (already consumed by main).
+fn parse_units(args: &[String]) -> Vec<UnitSpec> {
+    let mut units = Vec::new();
+    let mut i = 0;
+
+    while i < args.len() {
+        if args[i] != "--unit" {
+            i += 1;
+            continue;
+        }
+
+        let name = args.get(i + 1).unwrap_or_else(|| {
+            eprintln!("--unit requires a name");
+            process::exit(1);
+        });
+        if args.get(i + 2).map(String::as_str) != Some("--") {
+            eprintln!("--unit {} must be followed by -- <cmd...>", name);
+            process::exit(1);
+        }
+
+        let cmd_start = i + 3;
+        let mut cmd_end = cmd_start;
+        while cmd_end < args.len() && args[cmd_end] != "--unit" {
+            cmd_end += 1;
+        }
+
+        let cmd = args[cmd_start..cmd_end].to_vec();
+        if cmd.is_empty() {
+            eprintln!("--unit {} has no command", name);
+            process::exit(1);
+        }
+
+        units.push(UnitSpec { name: name.clone(), cmd });
+        i = cmd_end;
+    }
+
+    units
+}
+
+// What a unit's supervising thread reports back to the coordinator.
+enum UnitEventKind {
+    Ready,
+    Stopping,
+    Exited(i32),
+}
+
+struct UnitEvent {
+    name: String,
+    kind: UnitEventKind,
+}
+
+fn run_supervisor_mode(args: &[String]) {
+    let units = parse_units(&args[1..]);
+    if units.is_empty() {
+        eprintln!("Usage: {} [--verbose] --unit <name> -- <cmd...> [--unit <name> -- <cmd...> ...]", args[0]);
+        process::exit(1);
+    }
+
+    let shared_dir_base = env::var("SHARED_DIR")
+        .unwrap_or_else(|_| "/tmp/container-notify".to_string());
+    let timeout: u64 = env::var("TIMEOUT")
+        .unwrap_or_else(|_| "60".to_string())
+        .parse()
+        .unwrap_or(60);
+    let watchdog_interval: Option<Duration> = env::var("WATCHDOG_USEC")
+        .ok()
+        .and_then(|v| v.parse::<u64>().ok())
+        .map(Duration::from_micros);
+
+    info!(
+        "Supervising {} unit(s): {}",
+        units.len(),
+        units.iter().map(|u| u.name.as_str()).collect::<Vec<_>>().join(", ")
+    );
+    info!("Shared directory: {}", shared_dir_base);
+    info!("Timeout: {}s", timeout);
+
+    fs::create_dir_all(&shared_dir_base)
+        .expect("Failed to create shared directory");
+
+    let shared_dir_cleanup = shared_dir_base.clone();
+    ctrlc::set_handler(move || {
+        info!("Received interrupt signal, cleaning up...");
+        let _ = fs::remove_dir_all(&shared_dir_cleanup);
+        process::exit(1);
+    }).expect("Failed to set signal handler");
+
+    run_supervisor(units, shared_dir_base, timeout, watchdog_interval);
+}
+
+// Spawns one unit's container and its own event-monitoring thread, and
+// returns a handle the coordinator can use to kill it later. Mirrors
+// run_single's per-container loop, but reports transitions back over `tx`
+// instead of acting on them directly, so the coordinator can apply barrier
+// and aggregation semantics across every unit.
+fn supervise_unit(
+    unit: UnitSpec,
+    shared_dir: String,
+    timeout: u64,
+    last_heard: Arc<Mutex<HashMap<String, Instant>>>,
+    tx: mpsc::Sender<UnitEvent>,
+) -> Arc<Mutex<Container>> {
+    let name = unit.name;
+    let logs_socket_path = format!("{}/logs.sock", shared_dir);
+    let events_socket_path = format!("{}/events.sock", shared_dir);
+
+    fs::create_dir_all(&shared_dir)
+        .unwrap_or_else(|e| panic!("[{}] Failed to create shared directory: {}", name, e));
+
+    let _ = fs::remove_file(&logs_socket_path);
+    let logs_socket = UnixDatagram::bind(&logs_socket_path)
+        .unwrap_or_else(|e| panic!("[{}] Failed to create logs socket: {}", name, e));
+    let forwarder_name = name.clone();
+    thread::spawn(move || run_log_forwarder(&forwarder_name, logs_socket));
+
+    let _ = fs::remove_file(&events_socket_path);
+    let events_socket = UnixDatagram::bind(&events_socket_path)
+        .unwrap_or_else(|e| panic!("[{}] Failed to create events socket: {}", name, e));
+    events_socket
+        .set_read_timeout(Some(Duration::from_millis(200)))
+        .expect("Failed to set events socket read timeout");
+
+    info!("[{}] Starting container process: {}", name, unit.cmd.join(" "));
+    let child = Command::new(&unit.cmd[0])
+        .args(&unit.cmd[1..])
+        // Each unit gets its own isolated shared_dir ({base}/{name}); without
+        // this the child inherits the operator's base SHARED_DIR and binds
+        // sockets the host never listens on, so no unit can ever signal
+        // READY and the whole group times out.
+        .env("SHARED_DIR", &shared_dir)
+        .spawn()
+        .unwrap_or_else(|e| panic!("[{}] Failed to start container command: {}", name, e));
+
+    let container = Arc::new(Mutex::new(Container::Spawned(child)));
+    let container_for_thread = Arc::clone(&container);
+
+    thread::spawn(move || {
+        let start_time = Instant::now();
+        let mut effective_timeout = Duration::from_secs(timeout);
+        let mut ready_sent = false;
+
+        loop {
+            if start_time.elapsed() >= effective_timeout && !ready_sent {
+                error!("[{}] Timeout waiting for container to be ready", name);
+                container_for_thread.lock().unwrap().kill();
+                let _ = tx.send(UnitEvent { name, kind: UnitEventKind::Exited(1) });
+                return;
+            }
+
+            match container_for_thread.lock().unwrap().try_wait() {
+                Ok(Some(exit_code)) => {
+                    info!("[{}] Container exited with code {}", name, exit_code);
+                    let _ = tx.send(UnitEvent { name, kind: UnitEventKind::Exited(exit_code) });
+                    return;
+                }
+                Ok(None) => {}
+                Err(e) => {
+                    error!("[{}] Failed to check container status: {}", name, e);
+                    let _ = tx.send(UnitEvent { name, kind: UnitEventKind::Exited(1) });
+                    return;
+                }
+            }
+
+            let mut buffer = [0u8; 4096];
+            match events_socket.recv(&mut buffer) {
+                Ok(size) => {
+                    let status = String::from_utf8_lossy(&buffer[..size]).trim().to_string();
+                    info!("[{}] Event received: {}", name, status);
+                    last_heard.lock().unwrap().insert(name.clone(), Instant::now());
+
+                    match status.as_str() {
+                        "READY" => {
+                            if !ready_sent {
+                                ready_sent = true;
+                                let _ = tx.send(UnitEvent { name: name.clone(), kind: UnitEventKind::Ready });
+                            }
+                        }
+                        "STOPPING" => {
+                            let _ = tx.send(UnitEvent { name: name.clone(), kind: UnitEventKind::Stopping });
+                        }
+                        "WATCHDOG" => {
+                            // Liveness was already recorded above; the
+                            // coordinator's watchdog thread is the only
+                            // thing that forwards WATCHDOG=1 to systemd, so
+                            // one unit's ping can't mask another gone stale.
+                            info!("[{}] Watchdog ping received", name);
+                        }
+                        status if status.starts_with("STATUS:") => {
+                            let status_msg = &status[7..];
+                            if let Err(e) = notify_status(status_msg) {
+                                error!("[{}] Failed to forward status: {}", name, e);
+                            }
+                        }
+                        status if status.starts_with("EXTEND:") => {
+                            let usec_str = &status[7..];
+                            if let Ok(usec) = usec_str.parse::<u64>() {
+                                let extension = Duration::from_micros(usec);
+                                info!("[{}] Extending readiness deadline by {:?}", name, extension);
+                                effective_timeout += extension;
+                            } else {
+                                error!("[{}] Invalid EXTEND_TIMEOUT_USEC value: {}", name, status);
+                            }
+                        }
+                        status if status.starts_with("MESSAGE:") => {
+                            info!("[{}] Container message: {}", name, &status[8..]);
+                        }
+                        _ => {
+                            info!("[{}] Unknown status: {}", name, status);
+                        }
+                    }
+                }
+                Err(e) if e.kind() == io::ErrorKind::WouldBlock || e.kind() == io::ErrorKind::TimedOut => {
+                    // No event within the read timeout; loop around to
+                    // re-check the deadline and container liveness.
+                }
+                Err(e) => {
+                    error!("[{}] Events socket error: {}", name, e);
+                    let _ = tx.send(UnitEvent { name: name.clone(), kind: UnitEventKind::Exited(1) });
+                    return;
+                }
+            }
+        }
+    });
+
+    container
+}
+
+// Coordinates every unit's supervising thread: only notifies systemd READY
+// once all units have reached it (barrier semantics), forwards the first
+// STOPPING seen from any unit, and on the first non-zero exit kills the
+// rest, cleans up, and exits with that unit's code.
+fn run_supervisor(
+    units: Vec<UnitSpec>,
+    shared_dir_base: String,
+    timeout: u64,
+    watchdog_interval: Option<Duration>,
+) {
+    let (tx, rx) = mpsc::channel();
+    // Per-unit liveness, keyed by unit name, so the watchdog can require
+    // every unit to be alive rather than any single one.
+    let last_heard: Arc<Mutex<HashMap<String, Instant>>> = Arc::new(Mutex::new(HashMap::new()));
+    let total = units.len();
+
+    let mut containers: HashMap<String, Arc<Mutex<Container>>> = HashMap::new();
+    // Units the barrier is still waiting to hear from: removed as each unit
+    // either signals READY or exits cleanly before doing so, so a unit that
+    // exits 0 pre-READY can't leave the barrier permanently short.
+    let mut awaiting_ready: HashSet<String> = HashSet::new();
+    for unit in units {
+        let shared_dir = format!("{}/{}", shared_dir_base, unit.name);
+        let name = unit.name.clone();
+        awaiting_ready.insert(name.clone());
+        last_heard.lock().unwrap().insert(name.clone(), Instant::now());
+        let container = supervise_unit(unit, shared_dir, timeout, Arc::clone(&last_heard), tx.clone());
+        containers.insert(name, container);
+    }
+    drop(tx);
+
+    let mut ready: HashSet<String> = HashSet::new();
+    let mut ready_notified = false;
+    let mut stopping_notified = false;
+
+    // Notifies systemd READY once every unit has either reached it or
+    // dropped out of the barrier (clean pre-READY exit), and starts the
+    // watchdog. Only fires once.
+    let try_notify_barrier_ready = |ready_notified: &mut bool| {
+        if *ready_notified {
+            return;
+        }
+        info!("All units ready! Notifying systemd...");
+        if let Err(e) = notify_ready() {
+            error!("Failed to notify systemd ready: {}", e);
+            return;
+        }
+        *ready_notified = true;
+        if let Some(interval) = watchdog_interval {
+            let last_heard = Arc::clone(&last_heard);
+            thread::spawn(move || run_watchdog_multi(interval, last_heard));
+        }
+    };
+
+    for event in rx {
+        match event.kind {
+            UnitEventKind::Ready => {
+                ready.insert(event.name.clone());
+                awaiting_ready.remove(&event.name);
+                info!("Unit '{}' is ready ({}/{})", event.name, ready.len(), total);
+
+                if awaiting_ready.is_empty() {
+                    try_notify_barrier_ready(&mut ready_notified);
+                }
+            }
+            UnitEventKind::Stopping => {
+                if !stopping_notified {
+                    info!("Unit '{}' is stopping, forwarding to systemd", event.name);
+                    if let Err(e) = notify_stopping() {
+                        error!("Failed to notify systemd stopping: {}", e);
+                    }
+                    stopping_notified = true;
+                }
+            }
+            UnitEventKind::Exited(code) => {
+                info!("Unit '{}' exited with code {}", event.name, code);
+                containers.remove(&event.name);
+                // A unit that's gone no longer has liveness to track, and
+                // leaving it in the map would wedge the watchdog forever.
+                last_heard.lock().unwrap().remove(&event.name);
+
+                if code != 0 {
+                    error!("Unit '{}' failed (code {}), shutting down remaining units", event.name, code);
+                    for (name, container) in &containers {
+                        info!("Killing unit '{}'", name);
+                        container.lock().unwrap().kill();
+                    }
+                    let _ = fs::remove_dir_all(&shared_dir_base);
+                    process::exit(code.clamp(1, 255));
+                }
+
+                // A clean exit before READY drops this unit out of the
+                // barrier denominator - it can no longer signal readiness.
+                if awaiting_ready.remove(&event.name) && awaiting_ready.is_empty() {
+                    try_notify_barrier_ready(&mut ready_notified);
+                }
+
+                if containers.is_empty() {
+                    info!("All units exited cleanly");
+                    let _ = fs::remove_dir_all(&shared_dir_base);
+                    process::exit(0);
+                }
+            }
+        }
+    }
+
+    error!("All unit threads ended without reporting exit");
+    process::exit(1);
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(v: &[&str]) -> Vec<String> {
+        v.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn parse_units_single() {
+        let units = parse_units(&args(&["--unit", "web", "--", "echo", "hi"]));
+        assert_eq!(units.len(), 1);
+        assert_eq!(units[0].name, "web");
+        assert_eq!(units[0].cmd, vec!["echo", "hi"]);
+    }
+
+    #[test]
+    fn parse_units_multiple() {
+        let units = parse_units(&args(&[
+            "--unit", "web", "--", "echo", "hi",
+            "--unit", "db", "--", "true",
+        ]));
+        assert_eq!(units.len(), 2);
+        assert_eq!(units[0].name, "web");
+        assert_eq!(units[0].cmd, vec!["echo", "hi"]);
+        assert_eq!(units[1].name, "db");
+        assert_eq!(units[1].cmd, vec!["true"]);
+    }
+
+    #[test]
+    fn parse_units_no_groups_is_empty() {
+        assert!(parse_units(&args(&["--verbose"])).is_empty());
+    }
+}
@@ -0,0 +1,7 @@
+pub mod log_record;
+pub mod logger;
+pub mod reload;
+
+pub use log_record::LogRecord;
+pub use logger::SimpleLogger;
+pub use reload::{reexec, restore_from_env, ReloadState};
@@ -0,0 +1,48 @@
+use serde::{Deserialize, Serialize};
+
+// A single line forwarded from the container's stderr, shipped to `logs.sock`
+// as one JSON object per datagram.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LogRecord {
+    pub level: String,
+    #[serde(default)]
+    pub target: Option<String>,
+    pub message: String,
+}
+
+impl LogRecord {
+    pub fn info(message: impl Into<String>) -> Self {
+        Self {
+            level: "info".to_string(),
+            target: None,
+            message: message.into(),
+        }
+    }
+
+    // Parses a line emitted by the wrapped process: a well-formed `LogRecord`
+    // is passed through as-is, anything else is wrapped as a raw info line.
+    pub fn from_line(line: &str) -> Self {
+        serde_json::from_str(line).unwrap_or_else(|_| LogRecord::info(line))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_line_passes_through_valid_json() {
+        let record = LogRecord::from_line(r#"{"level":"warn","target":"app","message":"low disk"}"#);
+        assert_eq!(record.level, "warn");
+        assert_eq!(record.target.as_deref(), Some("app"));
+        assert_eq!(record.message, "low disk");
+    }
+
+    #[test]
+    fn from_line_wraps_plain_text_as_info() {
+        let record = LogRecord::from_line("starting up");
+        assert_eq!(record.level, "info");
+        assert_eq!(record.target, None);
+        assert_eq!(record.message, "starting up");
+    }
+}
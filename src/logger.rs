@@ -1,47 +1,126 @@
 use log::LevelFilter;
+use std::env;
 use std::io::{self, Write};
+use std::os::unix::net::{UnixDatagram, UnixStream};
+use std::sync::Mutex;
+
+// Where SimpleLogger writes: plain stderr (default), systemd's journal via
+// the sd_journal_stream_fd(3) streaming-socket protocol, or a syslog
+// datagram to /dev/log.
+enum Backend {
+    Stderr,
+    Journal(Mutex<UnixStream>),
+    Syslog(UnixDatagram),
+}
 
 // Simple logger implementation shared by both binaries
 pub struct SimpleLogger {
     level: LevelFilter,
+    backend: Backend,
 }
 
 impl SimpleLogger {
-    fn new(level: LevelFilter) -> Self {
-        Self { level }
+    fn new(level: LevelFilter, backend: Backend) -> Self {
+        Self { level, backend }
     }
-    
+
+    // Reads LOG_BACKEND=stderr|journal|syslog (default stderr) and wires up
+    // the matching sink. If the requested backend can't be reached (e.g. no
+    // systemd journal on this host), falls back to stderr.
     pub fn init(level: LevelFilter) {
-        let logger = SimpleLogger::new(level);
+        let backend = match env::var("LOG_BACKEND").as_deref() {
+            Ok("journal") => connect_journal().unwrap_or_else(|e| {
+                eprintln!("Failed to connect to systemd journal, falling back to stderr: {}", e);
+                Backend::Stderr
+            }),
+            Ok("syslog") => connect_syslog().unwrap_or_else(|e| {
+                eprintln!("Failed to connect to syslog, falling back to stderr: {}", e);
+                Backend::Stderr
+            }),
+            _ => Backend::Stderr,
+        };
+
+        let logger = SimpleLogger::new(level, backend);
         log::set_boxed_logger(Box::new(logger))
             .expect("Failed to set logger");
         log::set_max_level(level);
     }
 }
 
+// Opens a stream to systemd's journal following the protocol used by
+// sd_journal_stream_fd(3): a seven-line header (identifier, unit_id,
+// priority, level_prefix, forward_to_syslog, forward_to_kmsg,
+// forward_to_console), after which every line written to the socket
+// becomes a journal entry.
+fn connect_journal() -> io::Result<Backend> {
+    let mut stream = UnixStream::connect("/run/systemd/journal/stdout")?;
+    let identifier = env::args()
+        .next()
+        .unwrap_or_else(|| "distrobox-run-notify".to_string());
+    // identifier, unit_id (empty), default priority, level_prefix (lines
+    // carry their own "<N>" prefix), forward_to_syslog, forward_to_kmsg,
+    // forward_to_console - see sd_journal_stream_fd(3)
+    write!(stream, "{}\n\n6\n1\n0\n0\n0\n", identifier)?;
+    Ok(Backend::Journal(Mutex::new(stream)))
+}
+
+fn connect_syslog() -> io::Result<Backend> {
+    let socket = UnixDatagram::unbound()?;
+    socket.connect("/dev/log")?;
+    Ok(Backend::Syslog(socket))
+}
+
+// Maps a log::Level to its syslog priority (RFC 3164): error=3 ... info=6.
+fn syslog_priority(level: log::Level) -> u8 {
+    match level {
+        log::Level::Error => 3,
+        log::Level::Warn => 4,
+        log::Level::Info => 6,
+        log::Level::Debug | log::Level::Trace => 7,
+    }
+}
+
 impl log::Log for SimpleLogger {
     fn enabled(&self, metadata: &log::Metadata) -> bool {
         metadata.level() <= self.level
     }
 
     fn log(&self, record: &log::Record) {
-        if self.enabled(record.metadata()) {
-            let timestamp = std::time::SystemTime::now()
-                .duration_since(std::time::UNIX_EPOCH)
-                .unwrap()
-                .as_secs();
-            let hours = (timestamp % 86400) / 3600;
-            let minutes = (timestamp % 3600) / 60;
-            let seconds = timestamp % 60;
-            
-            eprintln!("[{} {:02}:{:02}:{:02}] {}", 
-                     record.target().to_uppercase(), 
-                     hours, minutes, seconds, 
-                     record.args());
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+
+        match &self.backend {
+            Backend::Stderr => {
+                let timestamp = std::time::SystemTime::now()
+                    .duration_since(std::time::UNIX_EPOCH)
+                    .unwrap()
+                    .as_secs();
+                let hours = (timestamp % 86400) / 3600;
+                let minutes = (timestamp % 3600) / 60;
+                let seconds = timestamp % 60;
+
+                eprintln!("[{} {:02}:{:02}:{:02}] {}",
+                         record.target().to_uppercase(),
+                         hours, minutes, seconds,
+                         record.args());
+            }
+            Backend::Journal(stream) => {
+                let line = format!("<{}>{}\n", syslog_priority(record.level()), record.args());
+                if let Ok(mut stream) = stream.lock() {
+                    let _ = stream.write_all(line.as_bytes());
+                }
+            }
+            Backend::Syslog(socket) => {
+                let line = format!("<{}>{}", syslog_priority(record.level()), record.args());
+                let _ = socket.send(line.as_bytes());
+            }
         }
     }
 
     fn flush(&self) {
-        io::stderr().flush().unwrap();
+        if let Backend::Stderr = self.backend {
+            io::stderr().flush().unwrap();
+        }
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,88 @@
+use std::env;
+use std::io;
+use std::os::unix::io::{AsRawFd, FromRawFd, RawFd};
+use std::os::unix::net::UnixDatagram;
+use std::os::unix::process::CommandExt;
+use std::process::Command;
+
+// Env vars used to hand live state down to the re-exec'd process on SIGHUP.
+const ENV_LOGS_FD: &str = "CONTAINER_NOTIFY_LOGS_FD";
+const ENV_EVENTS_FD: &str = "CONTAINER_NOTIFY_EVENTS_FD";
+const ENV_CONTAINER_PID: &str = "CONTAINER_NOTIFY_CONTAINER_PID";
+const ENV_READY_SENT: &str = "CONTAINER_NOTIFY_READY_SENT";
+
+// State the host waiter carries across a SIGHUP-triggered re-exec: the bound
+// logs and events sockets, the PID of the container it's already
+// supervising, and whether READY=1 was already sent (so we don't send it
+// twice).
+pub struct ReloadState {
+    pub logs_socket: UnixDatagram,
+    pub events_socket: UnixDatagram,
+    pub container_pid: u32,
+    pub ready_sent: bool,
+}
+
+// Reconstructs state handed down by a previous incarnation of this process,
+// if any. Returns `None` on a normal (non-reload) startup.
+pub fn restore_from_env() -> Option<ReloadState> {
+    let logs_fd: RawFd = env::var(ENV_LOGS_FD).ok()?.parse().ok()?;
+    let events_fd: RawFd = env::var(ENV_EVENTS_FD).ok()?.parse().ok()?;
+    let container_pid: u32 = env::var(ENV_CONTAINER_PID).ok()?.parse().ok()?;
+    let ready_sent = env::var(ENV_READY_SENT).as_deref() == Ok("1");
+
+    // Safety: both fds were bound by our own previous incarnation and handed
+    // down via execve with CLOEXEC cleared specifically so they'd survive.
+    let logs_socket = unsafe { UnixDatagram::from_raw_fd(logs_fd) };
+    let events_socket = unsafe { UnixDatagram::from_raw_fd(events_fd) };
+    Some(ReloadState {
+        logs_socket,
+        events_socket,
+        container_pid,
+        ready_sent,
+    })
+}
+
+// Clears CLOEXEC on both sockets' fds, stashes the live state into the
+// environment, then execve's the current binary in place. On success this
+// never returns; the new process picks everything back up via
+// `restore_from_env` without re-binding either socket or respawning the
+// container.
+pub fn reexec(
+    logs_socket: &UnixDatagram,
+    events_socket: &UnixDatagram,
+    container_pid: u32,
+    ready_sent: bool,
+) -> io::Error {
+    let logs_fd = logs_socket.as_raw_fd();
+    let events_fd = events_socket.as_raw_fd();
+    if let Err(e) = clear_cloexec(logs_fd).and_then(|_| clear_cloexec(events_fd)) {
+        return e;
+    }
+
+    unsafe {
+        env::set_var(ENV_LOGS_FD, logs_fd.to_string());
+        env::set_var(ENV_EVENTS_FD, events_fd.to_string());
+        env::set_var(ENV_CONTAINER_PID, container_pid.to_string());
+        env::set_var(ENV_READY_SENT, if ready_sent { "1" } else { "0" });
+    }
+
+    let exe = match env::current_exe() {
+        Ok(exe) => exe,
+        Err(e) => return e,
+    };
+    let args: Vec<String> = env::args().collect();
+    Command::new(exe).args(&args[1..]).exec()
+}
+
+fn clear_cloexec(fd: RawFd) -> io::Result<()> {
+    unsafe {
+        let flags = libc::fcntl(fd, libc::F_GETFD);
+        if flags < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        if libc::fcntl(fd, libc::F_SETFD, flags & !libc::FD_CLOEXEC) < 0 {
+            return Err(io::Error::last_os_error());
+        }
+    }
+    Ok(())
+}